@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use anyhow::{anyhow, Result};
+
+use crate::excel::{read_excel_file, DataValue};
+
+/// Caller-supplied progress for one activity seq.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ActivityProgress {
+    pub seq: u64,
+    /// Fraction complete in [0, 1], drives earned value.
+    pub percent_complete: f64,
+    /// Fraction of the work that *should* be done by now, in [0, 1]; drives
+    /// planned value. Defaults to fully planned (1.0) when omitted.
+    #[serde(default)]
+    pub planned_complete: Option<f64>,
+}
+
+/// Where to find the activity seq and cost on the budget and actuals sheets.
+/// IFS layouts vary between exports, so the caller pins the columns rather than
+/// us guessing.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EvmInput {
+    pub file_path: String,
+    #[serde(default = "default_budget_sheet")]
+    pub budget_sheet: String,
+    #[serde(default = "default_actual_sheet")]
+    pub actual_sheet: String,
+    pub seq_column: usize,
+    pub budget_cost_column: usize,
+    pub actual_cost_column: usize,
+    pub activities: Vec<ActivityProgress>,
+}
+
+fn default_budget_sheet() -> String {
+    "PT - Budgets".to_string()
+}
+
+fn default_actual_sheet() -> String {
+    "PT - Actuals".to_string()
+}
+
+/// Earned-value metrics for one activity or the project roll-up.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EvmMetrics {
+    pub seq: Option<u64>,
+    pub bac: f64,
+    pub pv: f64,
+    pub ev: f64,
+    pub ac: f64,
+    pub cv: f64,
+    pub sv: f64,
+    /// Cost/schedule performance indices and forecasts are `None` when their
+    /// denominator (AC or PV) is zero.
+    pub cpi: Option<f64>,
+    pub spi: Option<f64>,
+    pub eac: Option<f64>,
+    pub etc: Option<f64>,
+    pub vac: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EvmResult {
+    pub activities: Vec<EvmMetrics>,
+    pub total: EvmMetrics,
+}
+
+fn as_f64(value: &DataValue) -> f64 {
+    match value {
+        DataValue::Float(n) => *n,
+        DataValue::Int(i) => *i as f64,
+        DataValue::Text(s) => s.trim().parse().unwrap_or(0.0),
+        _ => 0.0,
+    }
+}
+
+/// Sum a cost column keyed by activity seq across a sheet's data rows.
+fn sum_by_seq(
+    sheets: &[crate::excel::ExcelData],
+    sheet_name: &str,
+    seq_column: usize,
+    cost_column: usize,
+) -> Result<HashMap<u64, f64>> {
+    let sheet = sheets
+        .iter()
+        .find(|s| s.sheet_name == sheet_name)
+        .ok_or_else(|| anyhow!("sheet '{}' not found in workbook", sheet_name))?;
+
+    let mut totals: HashMap<u64, f64> = HashMap::new();
+    for row in &sheet.rows {
+        let seq = match row.get(seq_column) {
+            Some(DataValue::Float(n)) => *n as u64,
+            Some(DataValue::Int(i)) => *i as u64,
+            _ => continue,
+        };
+        let cost = row.get(cost_column).map(as_f64).unwrap_or(0.0);
+        *totals.entry(seq).or_insert(0.0) += cost;
+    }
+    Ok(totals)
+}
+
+/// Assemble the metrics for a single activity from its raw EVM inputs.
+fn metrics_for(seq: Option<u64>, bac: f64, ev: f64, pv: f64, ac: f64) -> EvmMetrics {
+    let cpi = if ac != 0.0 { Some(ev / ac) } else { None };
+    let spi = if pv != 0.0 { Some(ev / pv) } else { None };
+    let eac = cpi.map(|cpi| bac / cpi);
+    let etc = eac.map(|eac| eac - ac);
+    let vac = eac.map(|eac| bac - eac);
+    EvmMetrics {
+        seq,
+        bac,
+        pv,
+        ev,
+        ac,
+        cv: ev - ac,
+        sv: ev - pv,
+        cpi,
+        spi,
+        eac,
+        etc,
+        vac,
+    }
+}
+
+/// Compute earned-value metrics per activity and for the project total by
+/// joining the budget and actuals sheets on activity seq.
+pub fn compute_evm(input: &EvmInput) -> Result<EvmResult> {
+    let sheets = read_excel_file(&input.file_path)?;
+    let budget = sum_by_seq(&sheets, &input.budget_sheet, input.seq_column, input.budget_cost_column)?;
+    let actual = sum_by_seq(&sheets, &input.actual_sheet, input.seq_column, input.actual_cost_column)?;
+
+    let mut activities = Vec::with_capacity(input.activities.len());
+    let (mut t_bac, mut t_pv, mut t_ev, mut t_ac) = (0.0, 0.0, 0.0, 0.0);
+
+    for progress in &input.activities {
+        let bac = budget.get(&progress.seq).copied().unwrap_or(0.0);
+        let ac = actual.get(&progress.seq).copied().unwrap_or(0.0);
+        let ev = progress.percent_complete * bac;
+        let pv = progress.planned_complete.unwrap_or(1.0) * bac;
+
+        t_bac += bac;
+        t_pv += pv;
+        t_ev += ev;
+        t_ac += ac;
+
+        activities.push(metrics_for(Some(progress.seq), bac, ev, pv, ac));
+    }
+
+    let total = metrics_for(None, t_bac, t_ev, t_pv, t_ac);
+    Ok(EvmResult { activities, total })
+}
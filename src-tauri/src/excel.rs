@@ -1,8 +1,73 @@
-use calamine::{open_workbook, Reader, Xlsx, Data};
-use rust_xlsxwriter::Workbook;
+use calamine::{open_workbook, Data, Ods, Reader, Xls, Xlsb, Xlsx};
+use rust_xlsxwriter::{Color, DataValidation, DataValidationRule, Format, Url, Workbook};
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
+use std::io::BufReader;
+use std::fs::File;
 use std::path::Path;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+
+/// Any spreadsheet reader calamine knows about, dispatched on file extension.
+///
+/// This mirrors calamine's own `open_workbook_auto` so the app can ingest the
+/// LibreOffice `.ods`, legacy `.xls`, and binary `.xlsb` files that IFS exports
+/// and users routinely send us, not just `.xlsx`.
+pub enum WorkbookReader {
+    Xlsx(Xlsx<BufReader<File>>),
+    Xls(Xls<BufReader<File>>),
+    Xlsb(Xlsb<BufReader<File>>),
+    Ods(Ods<BufReader<File>>),
+}
+
+impl WorkbookReader {
+    /// Open `path`, choosing the reader from its extension.
+    pub fn open(path: &Path) -> Result<Self> {
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_lowercase())
+            .unwrap_or_default();
+
+        let reader = match ext.as_str() {
+            "xls" | "xla" => WorkbookReader::Xls(open_workbook(path)?),
+            "xlsx" | "xlsm" | "xlam" => WorkbookReader::Xlsx(open_workbook(path)?),
+            "xlsb" => WorkbookReader::Xlsb(open_workbook(path)?),
+            "ods" => WorkbookReader::Ods(open_workbook(path)?),
+            other => return Err(anyhow!("unsupported spreadsheet format: .{}", other)),
+        };
+        Ok(reader)
+    }
+
+    pub fn sheet_names(&self) -> Vec<String> {
+        match self {
+            WorkbookReader::Xlsx(w) => w.sheet_names(),
+            WorkbookReader::Xls(w) => w.sheet_names(),
+            WorkbookReader::Xlsb(w) => w.sheet_names(),
+            WorkbookReader::Ods(w) => w.sheet_names(),
+        }
+    }
+
+    pub fn worksheet_range(&mut self, name: &str) -> Result<calamine::Range<Data>> {
+        let range = match self {
+            WorkbookReader::Xlsx(w) => w.worksheet_range(name)?,
+            WorkbookReader::Xls(w) => w.worksheet_range(name)?,
+            WorkbookReader::Xlsb(w) => w.worksheet_range(name)?,
+            WorkbookReader::Ods(w) => w.worksheet_range(name)?,
+        };
+        Ok(range)
+    }
+
+    /// The formula text for each cell in `name`; empty strings mark value-only cells.
+    pub fn worksheet_formula(&mut self, name: &str) -> Result<calamine::Range<String>> {
+        let range = match self {
+            WorkbookReader::Xlsx(w) => w.worksheet_formula(name)?,
+            WorkbookReader::Xls(w) => w.worksheet_formula(name)?,
+            WorkbookReader::Xlsb(w) => w.worksheet_formula(name)?,
+            WorkbookReader::Ods(w) => w.worksheet_formula(name)?,
+        };
+        Ok(range)
+    }
+}
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct StockData {
@@ -16,89 +81,324 @@ pub struct StockData {
     pub adjusted_close: Option<f64>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct ExcelData {
     pub headers: Vec<String>,
     pub rows: Vec<Vec<DataValue>>,
     pub sheet_name: String,
+    /// Optional presentation metadata, applied only when `write_excel_file`
+    /// writes `.xlsx`; the `.ods` writer ignores it and `column_widths`.
+    #[serde(default)]
+    pub styles: Vec<CellStyle>,
+    /// Per-column widths in character units, indexed by column.
+    #[serde(default)]
+    pub column_widths: Vec<ColumnWidth>,
 }
 
+/// A data-validation constraint on a cell.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Validation {
+    /// Restrict input to one of a fixed list of strings (a dropdown).
+    List { values: Vec<String> },
+    /// Restrict input to a numeric range, inclusive.
+    NumberRange { min: f64, max: f64 },
+}
+
+/// Presentation metadata for a single cell, emitted on write.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CellStyle {
+    pub row: u32,
+    pub col: u16,
+    /// `#RRGGBB` background fill.
+    #[serde(default)]
+    pub background_color: Option<String>,
+    /// `#RRGGBB` font color.
+    #[serde(default)]
+    pub font_color: Option<String>,
+    /// Turn the cell into a hyperlink pointing here.
+    #[serde(default)]
+    pub hyperlink: Option<String>,
+    #[serde(default)]
+    pub validation: Option<Validation>,
+}
+
+/// Width for one column, in character units.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColumnWidth {
+    pub col: u16,
+    pub width: f64,
+}
+
+/// A single cell carrying both its evaluated value and, when present, the
+/// formula that produced it. The Workingsheet roll-ups (remaining = budget −
+/// actual) are driven by formulas the plain value read throws away; surfacing
+/// both lets the frontend explain each number and flag formulas a manual
+/// `update_cell` has since overwritten.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CellWithFormula {
+    pub value: DataValue,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formula: Option<String>,
+}
+
+/// Per-sheet companion to [`ExcelData`] that keeps each cell's formula text.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExcelDataWithFormulas {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<CellWithFormula>>,
+    pub sheet_name: String,
+}
+
+/// A typed spreadsheet cell.
+///
+/// Excel stores dates, money, and hour quantities as bare numbers or symbol-
+/// prefixed strings, so reading them back as `Float`/`Text` throws away their
+/// meaning and forces every analysis site to reformat by hand. Carrying the
+/// domain type through lets budget-vs-actual math stay numeric while dates,
+/// durations, and currency display and sort correctly.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(untagged)]
 pub enum DataValue {
+    Int(i64),
+    Float(f64),
     Text(String),
-    Number(f64),
-    Integer(i64),
-    Boolean(bool),
+    Bool(bool),
+    /// An Excel date/time kept as its raw serial value so the exact number
+    /// round-trips, plus a flag recording that the source cell was formatted
+    /// as a date. Collapsing these to `Float` silently corrupted the
+    /// vacation/pipeline date columns, writing back a bare serial like 45123.
+    DateTime { serial: f64, is_date: bool },
+    Duration { hours: f64, minutes: f64 },
+    /// A money amount with its currency, kept distinct from `Float` so
+    /// budget/actual figures carry their unit (`$1,200` → `amount: 1200.0,
+    /// code: "USD"`) instead of collapsing to a bare number.
+    Currency { amount: f64, code: String },
     Empty,
 }
 
+/// Excel epoch offset (days between 1899-12-30 and the Unix epoch).
+const EXCEL_UNIX_EPOCH_DAYS: f64 = 25569.0;
+
+/// Convert an Excel date serial into a `NaiveDateTime`. The `25569`-day epoch
+/// offset (1899-12-30, not 1900-01-01) already absorbs Excel's phantom
+/// 1900-02-29, so serials from 61 (1900-03-01) on convert exactly with no
+/// further leap-year fixup. Serials below 61 come out one day early.
+pub(crate) fn serial_to_datetime(serial: f64) -> Option<NaiveDateTime> {
+    let secs = (serial - EXCEL_UNIX_EPOCH_DAYS) * 86400.0;
+    chrono::DateTime::from_timestamp(secs.floor() as i64, 0).map(|dt| dt.naive_utc())
+}
+
+/// Inverse of [`serial_to_datetime`]: a `NaiveDateTime` back to an Excel serial.
+fn datetime_to_serial(dt: NaiveDateTime) -> f64 {
+    let secs = dt.and_utc().timestamp() as f64;
+    secs / 86400.0 + EXCEL_UNIX_EPOCH_DAYS
+}
+
+/// Parse the hours/minutes out of an ISO 8601 duration string (the subset
+/// calamine emits for time cells, e.g. `PT1H30M` or `PT45M`), returning whole
+/// hours and the remaining minutes. Returns `None` if the string isn't a
+/// recognisable `PT…` duration.
+fn parse_iso_duration(s: &str) -> Option<(f64, f64)> {
+    let body = s.strip_prefix("PT").or_else(|| s.strip_prefix("pt"))?;
+    let mut hours = 0.0;
+    let mut minutes = 0.0;
+    let mut seconds = 0.0;
+    let mut num = String::new();
+    let mut saw_unit = false;
+    for ch in body.chars() {
+        match ch {
+            '0'..='9' | '.' => num.push(ch),
+            'H' | 'h' => { hours = num.parse().ok()?; num.clear(); saw_unit = true; }
+            'M' | 'm' => { minutes = num.parse().ok()?; num.clear(); saw_unit = true; }
+            'S' | 's' => { seconds = num.parse().ok()?; num.clear(); saw_unit = true; }
+            _ => return None,
+        }
+    }
+    if !saw_unit || !num.is_empty() {
+        return None;
+    }
+    let total_minutes = hours * 60.0 + minutes + seconds / 60.0;
+    let whole_hours = (total_minutes / 60.0).trunc();
+    Some((whole_hours, total_minutes - whole_hours * 60.0))
+}
+
+/// Known currency symbols/prefixes → ISO 4217 code, longest prefix first so
+/// `A$`/`R$` win over a bare `$`. IFS and LibreOffice exports routinely emit
+/// money as symbol-prefixed text (`$1,200`, `A$500`, `R$ 100`).
+const CURRENCY_PREFIXES: &[(&str, &str)] = &[
+    ("A$", "AUD"),
+    ("R$", "BRL"),
+    ("$", "USD"),
+    ("€", "EUR"),
+    ("£", "GBP"),
+    ("¥", "JPY"),
+];
+
+/// Detect a money value in a text cell: a leading currency symbol from
+/// `CURRENCY_PREFIXES` followed by a number (grouping commas/spaces allowed). Returns the amount
+/// and its ISO code, or `None` when the string isn't a recognisable amount.
+fn parse_currency(s: &str) -> Option<(f64, String)> {
+    let trimmed = s.trim();
+    let (code, rest) = CURRENCY_PREFIXES
+        .iter()
+        .find_map(|(sym, code)| trimmed.strip_prefix(sym).map(|rest| (*code, rest)))?;
+    let digits: String = rest.chars().filter(|c| !matches!(c, ',' | ' ')).collect();
+    if digits.is_empty() {
+        return None;
+    }
+    let amount = digits.parse::<f64>().ok()?;
+    Some((amount, code.to_string()))
+}
+
+/// A display number-format string for a currency code, used when writing the
+/// amount back so the cell still shows its symbol.
+fn currency_num_format(code: &str) -> &'static str {
+    match code {
+        "EUR" => "€#,##0.00",
+        "GBP" => "£#,##0.00",
+        "JPY" => "¥#,##0",
+        _ => "$#,##0.00",
+    }
+}
+
 impl From<Data> for DataValue {
     fn from(dt: Data) -> Self {
         match dt {
-            Data::String(s) => DataValue::Text(s),
-            Data::Float(f) => DataValue::Number(f),
-            Data::Int(i) => DataValue::Integer(i),
-            Data::Bool(b) => DataValue::Boolean(b),
+            // A symbol-prefixed amount (`$1,200`, `A$500`) carries its currency;
+            // plain text falls through unchanged.
+            Data::String(s) => parse_currency(&s)
+                .map(|(amount, code)| DataValue::Currency { amount, code })
+                .unwrap_or(DataValue::Text(s)),
+            Data::Float(f) => DataValue::Float(f),
+            Data::Int(i) => DataValue::Int(i),
+            Data::Bool(b) => DataValue::Bool(b),
             Data::Empty => DataValue::Empty,
             Data::Error(_) => DataValue::Empty,
-            Data::DateTime(d) => DataValue::Number(d.as_f64()),
-            Data::DateTimeIso(s) => DataValue::Text(s),
-            Data::DurationIso(s) => DataValue::Text(s),
+            // calamine already classifies serial-number cells from the number
+            // format: duration-formatted cells read as hour quantities, the
+            // rest as dates. Keep the raw serial so dates round-trip exactly.
+            Data::DateTime(d) if d.is_duration() => {
+                let hours = d.as_f64() * 24.0;
+                DataValue::Duration { hours: hours.trunc(), minutes: (hours.fract() * 60.0).round() }
+            }
+            Data::DateTime(d) => DataValue::DateTime { serial: d.as_f64(), is_date: d.is_datetime() },
+            Data::DateTimeIso(s) => s
+                .parse::<NaiveDateTime>()
+                .map(|ndt| DataValue::DateTime { serial: datetime_to_serial(ndt), is_date: true })
+                .unwrap_or(DataValue::Text(s)),
+            // An ISO 8601 duration (e.g. `PT1H30M`) is an hour quantity, not text.
+            Data::DurationIso(s) => parse_iso_duration(&s)
+                .map(|(hours, minutes)| DataValue::Duration { hours, minutes })
+                .unwrap_or(DataValue::Text(s)),
         }
     }
 }
 
+/// Row index (zero-based) where a sheet's headers live, by sheet-name heuristic.
+fn header_row_index(sheet_name: &str) -> usize {
+    let name = sheet_name.to_lowercase();
+    if name.contains("pipeline") {
+        10 // Pipeline headers in row 11
+    } else if name.contains("program") && !name.contains("vacation") {
+        2 // Program Management headers in row 3
+    } else {
+        0 // Default headers in row 1
+    }
+}
+
+/// How to locate the header row when reading a workbook.
+///
+/// The defaults reproduce the historical sheet-name heuristic so existing
+/// callers are unaffected; set the fields to drive header resolution from the
+/// frontend instead of guessing from the sheet name.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ReadOptions {
+    /// Per-sheet-name header row, **1-based**; `0` means "no header, synthesize
+    /// `Col0..ColN`". Takes precedence over `default_header_row`.
+    #[serde(default)]
+    pub header_rows: std::collections::HashMap<String, usize>,
+    /// Global header row (1-based, `0` = synthesize) applied to sheets with no
+    /// per-sheet entry. `None` falls back to the sheet-name heuristic.
+    #[serde(default)]
+    pub default_header_row: Option<usize>,
+    /// When no explicit header row is set, auto-detect the first non-empty row
+    /// as the header rather than using the heuristic.
+    #[serde(default)]
+    pub skip_empty_leading_rows: bool,
+}
+
+/// Resolved header location: `Some(idx)` reads row `idx` as the header,
+/// `None` synthesizes `Col0..ColN`.
+fn resolve_header_index(
+    options: &ReadOptions,
+    sheet_name: &str,
+    rows: &[Vec<DataValue>],
+) -> Option<usize> {
+    let spec = options
+        .header_rows
+        .get(sheet_name)
+        .copied()
+        .or(options.default_header_row);
+
+    match spec {
+        Some(0) => None,            // explicit "no header"
+        Some(one_based) => Some(one_based - 1),
+        None if options.skip_empty_leading_rows => rows
+            .iter()
+            .position(|row| row.iter().any(|v| !matches!(v, DataValue::Empty))),
+        // Heuristic path: vacation sheets have no header row and synthesize.
+        None if sheet_name.to_lowercase().contains("vacation") => None,
+        None => Some(header_row_index(sheet_name)),
+    }
+}
+
 pub fn read_excel_file(file_path: &str) -> Result<Vec<ExcelData>> {
+    read_excel_file_with_options(file_path, &ReadOptions::default())
+}
+
+/// Read every sheet, resolving each sheet's header row per `options`.
+pub fn read_excel_file_with_options(
+    file_path: &str,
+    options: &ReadOptions,
+) -> Result<Vec<ExcelData>> {
     let path = Path::new(file_path);
-    let mut workbook: Xlsx<_> = open_workbook(path)?;
+    let mut workbook = WorkbookReader::open(path)?;
     let mut all_sheets = Vec::new();
 
     for sheet_name in workbook.sheet_names() {
         if let Ok(range) = workbook.worksheet_range(&sheet_name) {
-            let mut headers = Vec::new();
-            let mut rows = Vec::new();
-            
-            // Special handling for different sheets with different header rows
-            let header_row_index = if sheet_name.to_lowercase().contains("pipeline") { 
-                10  // Pipeline headers in row 11
-            } else if sheet_name.to_lowercase().contains("program") && !sheet_name.to_lowercase().contains("vacation") { 
-                2   // Program Management headers in row 3
-            } else { 
-                0   // Default headers in row 1
-            };
+            let rows: Vec<Vec<DataValue>> = range
+                .rows()
+                .map(|row| row.iter().map(|cell| DataValue::from(cell.clone())).collect())
+                .collect();
 
-            for (row_idx, row) in range.rows().enumerate() {
-                let row_data: Vec<DataValue> = row.iter()
-                    .map(|cell| DataValue::from(cell.clone()))
-                    .collect();
-
-                // Always add the row to rows array
-                rows.push(row_data.clone());
-                
-                // Also extract headers for the headers field
-                if row_idx == header_row_index && !sheet_name.to_lowercase().contains("vacation") {
-                    headers = row_data.iter()
-                        .map(|v| match v {
-                            DataValue::Text(s) => s.clone(),
-                            DataValue::Number(n) => n.to_string(),
-                            _ => String::new(),
-                        })
-                        .collect();
-                }
-            }
-            
-            // For vacation sheet, create dummy headers since we're including all data
-            if sheet_name.to_lowercase().contains("vacation") && headers.is_empty() {
-                if let Some(first_row) = rows.first() {
-                    headers = (0..first_row.len()).map(|i| format!("Col{}", i)).collect();
-                }
-            }
+            let header_index = resolve_header_index(options, &sheet_name, &rows);
+            let headers = match header_index {
+                Some(idx) => rows
+                    .get(idx)
+                    .map(|row| {
+                        row.iter()
+                            .map(|v| match v {
+                                DataValue::Text(s) => s.clone(),
+                                DataValue::Float(n) => n.to_string(),
+                                DataValue::Int(n) => n.to_string(),
+                                _ => String::new(),
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                None => rows
+                    .first()
+                    .map(|first| (0..first.len()).map(|i| format!("Col{}", i)).collect())
+                    .unwrap_or_default(),
+            };
 
             all_sheets.push(ExcelData {
                 headers,
                 rows,
                 sheet_name: sheet_name.clone(),
+                ..Default::default()
             });
         }
     }
@@ -106,7 +406,115 @@ pub fn read_excel_file(file_path: &str) -> Result<Vec<ExcelData>> {
     Ok(all_sheets)
 }
 
+/// Read every sheet keeping both the evaluated value and the formula text for
+/// each cell. Header resolution matches [`read_excel_file`] so the two views
+/// line up row-for-row.
+pub fn read_excel_with_formulas(file_path: &str) -> Result<Vec<ExcelDataWithFormulas>> {
+    let path = Path::new(file_path);
+    let mut workbook = WorkbookReader::open(path)?;
+    let mut all_sheets = Vec::new();
+
+    for sheet_name in workbook.sheet_names() {
+        let range = match workbook.worksheet_range(&sheet_name) {
+            Ok(range) => range,
+            Err(_) => continue,
+        };
+        // `worksheet_formula` is absent for legacy/ODS sources; treat a failure
+        // as "no formulas" rather than aborting the whole read.
+        let formulas = workbook.worksheet_formula(&sheet_name).ok();
+
+        // The value range and the formula range can have different origins when
+        // the used area doesn't start at A1, so look formulas up by absolute
+        // cell coordinate (value-range origin + offset) rather than by the
+        // value range's own relative index.
+        let (row_origin, col_origin) = range.start().unwrap_or((0, 0));
+
+        let header_row_index = header_row_index(&sheet_name);
+        let mut headers = Vec::new();
+        let mut rows = Vec::new();
+
+        for (row_idx, row) in range.rows().enumerate() {
+            let row_data: Vec<CellWithFormula> = row
+                .iter()
+                .enumerate()
+                .map(|(col_idx, cell)| {
+                    let abs_row = row_origin + row_idx as u32;
+                    let abs_col = col_origin + col_idx as u32;
+                    let formula = formulas
+                        .as_ref()
+                        .and_then(|f| f.get_value((abs_row, abs_col)))
+                        .filter(|s| !s.is_empty())
+                        .cloned();
+                    CellWithFormula {
+                        value: DataValue::from(cell.clone()),
+                        formula,
+                    }
+                })
+                .collect();
+
+            if row_idx == header_row_index && !sheet_name.to_lowercase().contains("vacation") {
+                headers = row_data
+                    .iter()
+                    .map(|c| match &c.value {
+                        DataValue::Text(s) => s.clone(),
+                        DataValue::Float(n) => n.to_string(),
+                        DataValue::Int(n) => n.to_string(),
+                        _ => String::new(),
+                    })
+                    .collect();
+            }
+
+            rows.push(row_data);
+        }
+
+        if sheet_name.to_lowercase().contains("vacation") && headers.is_empty() {
+            if let Some(first_row) = rows.first() {
+                headers = (0..first_row.len()).map(|i| format!("Col{}", i)).collect();
+            }
+        }
+
+        all_sheets.push(ExcelDataWithFormulas {
+            headers,
+            rows,
+            sheet_name: sheet_name.clone(),
+        });
+    }
+
+    Ok(all_sheets)
+}
+
+/// The on-disk spreadsheet format, used to dispatch reads and writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SpreadsheetFormat {
+    Xlsx,
+    Ods,
+}
+
+impl SpreadsheetFormat {
+    /// Detect the format from a path's extension, defaulting to `Xlsx`.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase()) {
+            Some(ext) if ext == "ods" => SpreadsheetFormat::Ods,
+            _ => SpreadsheetFormat::Xlsx,
+        }
+    }
+}
+
+/// Write `data` to `file_path`, picking the format from the extension.
 pub fn write_excel_file(file_path: &str, data: Vec<ExcelData>) -> Result<()> {
+    let format = SpreadsheetFormat::from_path(Path::new(file_path));
+    write_excel_file_as(file_path, data, format)
+}
+
+/// Write `data` to `file_path` in an explicitly chosen `format`.
+pub fn write_excel_file_as(file_path: &str, data: Vec<ExcelData>, format: SpreadsheetFormat) -> Result<()> {
+    match format {
+        SpreadsheetFormat::Xlsx => write_xlsx(file_path, data),
+        SpreadsheetFormat::Ods => write_ods_file(file_path, data),
+    }
+}
+
+fn write_xlsx(file_path: &str, data: Vec<ExcelData>) -> Result<()> {
     let mut workbook = Workbook::new();
 
     for sheet_data in data {
@@ -118,28 +526,183 @@ pub fn write_excel_file(file_path: &str, data: Vec<ExcelData>) -> Result<()> {
             worksheet.write_string(0, col as u16, header)?;
         }
 
+        // Index presentation metadata by cell so the write loop can apply it.
+        let styles: std::collections::HashMap<(u32, u16), &CellStyle> = sheet_data
+            .styles
+            .iter()
+            .map(|s| ((s.row, s.col), s))
+            .collect();
+
         // Write data rows
         for (row_idx, row) in sheet_data.rows.iter().enumerate() {
             for (col_idx, value) in row.iter().enumerate() {
                 let row_num = (row_idx + 1) as u32;
                 let col_num = col_idx as u16;
 
+                let style = styles.get(&(row_num, col_num)).copied();
+                let format = cell_format(style);
+
+                // A hyperlink replaces the cell's value with a clickable URL.
+                if let Some(link) = style.and_then(|s| s.hyperlink.as_deref()) {
+                    worksheet.write_url_with_format(row_num, col_num, Url::new(link), &format)?;
+                    continue;
+                }
+
                 match value {
-                    DataValue::Text(s) => { worksheet.write_string(row_num, col_num, s)?; },
-                    DataValue::Number(n) => { worksheet.write_number(row_num, col_num, *n)?; },
-                    DataValue::Integer(i) => { worksheet.write_number(row_num, col_num, *i as f64)?; },
-                    DataValue::Boolean(b) => { worksheet.write_boolean(row_num, col_num, *b)?; },
+                    DataValue::Text(s) => { worksheet.write_string_with_format(row_num, col_num, s, &format)?; },
+                    DataValue::Float(n) => { worksheet.write_number_with_format(row_num, col_num, *n, &format)?; },
+                    DataValue::Int(i) => { worksheet.write_number_with_format(row_num, col_num, *i as f64, &format)?; },
+                    DataValue::Bool(b) => { worksheet.write_boolean_with_format(row_num, col_num, *b, &format)?; },
+                    // Round-trip the serial through a real datetime and attach
+                    // a date number format so the cell still displays as a date
+                    // rather than a bare serial number.
+                    DataValue::DateTime { serial, is_date } => {
+                        match (is_date, serial_to_datetime(*serial)) {
+                            (true, Some(dt)) => {
+                                let format = format.clone().set_num_format("yyyy-mm-dd");
+                                worksheet.write_datetime_with_format(row_num, col_num, dt, &format)?;
+                            }
+                            _ => { worksheet.write_number_with_format(row_num, col_num, *serial, &format)?; }
+                        }
+                    },
+                    // Hour quantities stay numeric (total hours) so roll-ups work.
+                    DataValue::Duration { hours, minutes } => {
+                        worksheet.write_number_with_format(row_num, col_num, hours + minutes / 60.0, &format)?;
+                    },
+                    // Keep the amount numeric and attach a currency number
+                    // format so it still displays with its unit.
+                    DataValue::Currency { amount, code } => {
+                        let format = format.clone().set_num_format(currency_num_format(code));
+                        worksheet.write_number_with_format(row_num, col_num, *amount, &format)?;
+                    },
                     DataValue::Empty => {},
                 }
             }
         }
+
+        // Per-column widths.
+        for cw in &sheet_data.column_widths {
+            worksheet.set_column_width(cw.col, cw.width)?;
+        }
+
+        // Data validations.
+        for style in &sheet_data.styles {
+            if let Some(validation) = &style.validation {
+                let dv = match validation {
+                    Validation::List { values } => DataValidation::new().allow_list_strings(values)?,
+                    Validation::NumberRange { min, max } => {
+                        DataValidation::new().allow_decimal_number(DataValidationRule::between(*min, *max))
+                    }
+                };
+                worksheet.add_data_validation(style.row, style.col, style.row, style.col, &dv)?;
+            }
+        }
     }
 
     workbook.save(file_path)?;
     Ok(())
 }
 
+/// Build a `Format` carrying a cell's background and font colors, if any.
+fn cell_format(style: Option<&CellStyle>) -> Format {
+    let mut format = Format::new();
+    if let Some(style) = style {
+        if let Some(color) = style.background_color.as_deref().and_then(parse_hex_color) {
+            format = format.set_background_color(color);
+        }
+        if let Some(color) = style.font_color.as_deref().and_then(parse_hex_color) {
+            format = format.set_font_color(color);
+        }
+    }
+    format
+}
+
+/// Parse a `#RRGGBB` (or `RRGGBB`) string into a `Color`.
+fn parse_hex_color(s: &str) -> Option<Color> {
+    let hex = s.trim_start_matches('#');
+    if hex.len() != 6 {
+        return None;
+    }
+    u32::from_str_radix(hex, 16).ok().map(Color::RGB)
+}
+
+fn write_ods_file(file_path: &str, data: Vec<ExcelData>) -> Result<()> {
+    use spreadsheet_ods::{write_ods, Sheet, WorkBook};
+
+    let mut workbook = WorkBook::new_empty();
+
+    for sheet_data in data {
+        let mut sheet = Sheet::new(&sheet_data.sheet_name);
+
+        for (col, header) in sheet_data.headers.iter().enumerate() {
+            sheet.set_value(0, col as u32, header.clone());
+        }
+
+        for (row_idx, row) in sheet_data.rows.iter().enumerate() {
+            for (col_idx, value) in row.iter().enumerate() {
+                let row_num = (row_idx + 1) as u32;
+                let col_num = col_idx as u32;
+
+                match value {
+                    DataValue::Text(s) => sheet.set_value(row_num, col_num, s.clone()),
+                    DataValue::Float(n) => sheet.set_value(row_num, col_num, *n),
+                    DataValue::Int(i) => sheet.set_value(row_num, col_num, *i as f64),
+                    DataValue::Bool(b) => sheet.set_value(row_num, col_num, *b),
+                    // ODS has a native date type; fall back to the serial if the
+                    // value doesn't convert.
+                    DataValue::DateTime { serial, is_date } => match (is_date, serial_to_datetime(*serial)) {
+                        (true, Some(dt)) => sheet.set_value(row_num, col_num, dt),
+                        _ => sheet.set_value(row_num, col_num, *serial),
+                    },
+                    DataValue::Duration { hours, minutes } => {
+                        sheet.set_value(row_num, col_num, hours + minutes / 60.0)
+                    }
+                    DataValue::Currency { amount, .. } => sheet.set_value(row_num, col_num, *amount),
+                    DataValue::Empty => {}
+                }
+            }
+        }
+
+        workbook.push_sheet(sheet);
+    }
+
+    write_ods(&mut workbook, file_path)?;
+    Ok(())
+}
+
+/// The plain-text form of a value for the in-place patcher, or `None` for
+/// types it can't represent (which forces the full-rewrite fallback).
+fn patch_text(value: &DataValue) -> Option<String> {
+    match value {
+        DataValue::Text(s) => Some(s.clone()),
+        DataValue::Float(n) => Some(n.to_string()),
+        DataValue::Int(i) => Some(i.to_string()),
+        DataValue::Bool(b) => Some(b.to_string()),
+        DataValue::Empty => Some(String::new()),
+        _ => None,
+    }
+}
+
 pub fn update_excel_cell(
+    file_path: &str,
+    sheet_name: &str,
+    row: usize,
+    col: usize,
+    value: DataValue,
+) -> Result<()> {
+    // Fast path: patch the single cell in place, preserving the workbook.
+    if SpreadsheetFormat::from_path(Path::new(file_path)) == SpreadsheetFormat::Xlsx {
+        if let Some(text) = patch_text(&value) {
+            let patches = vec![crate::xlsx_patch::CellPatch { row, col, value: text }];
+            if crate::xlsx_patch::patch_cells(file_path, sheet_name, &patches).is_ok() {
+                return Ok(());
+            }
+        }
+    }
+    update_excel_cell_full(file_path, sheet_name, row, col, value)
+}
+
+fn update_excel_cell_full(
     file_path: &str,
     sheet_name: &str,
     row: usize,
@@ -147,7 +710,7 @@ pub fn update_excel_cell(
     value: DataValue
 ) -> Result<()> {
     let mut sheets = read_excel_file(file_path)?;
-    
+
     for sheet in &mut sheets {
         if sheet.sheet_name == sheet_name {
             if row > 0 && row <= sheet.rows.len() {
@@ -169,15 +732,54 @@ pub struct CellUpdate {
     pub column: usize,
     pub value: String,
     pub header: Option<String>,
+    /// Optional presentation metadata to attach to the cell on write.
+    #[serde(default)]
+    pub background_color: Option<String>,
+    #[serde(default)]
+    pub font_color: Option<String>,
+    #[serde(default)]
+    pub hyperlink: Option<String>,
+    #[serde(default)]
+    pub validation: Option<Validation>,
 }
 
 pub fn update_excel_cells(
     file_path: &str,
     sheet_name: &str,
     updates: Vec<CellUpdate>
+) -> Result<()> {
+    // In-place patching handles plain value edits in a single open/modify/save
+    // cycle. Presentation metadata isn't expressible as a simple cell patch, so
+    // any styled update sends the whole batch down the full-rewrite path.
+    let styled = updates.iter().any(|u| {
+        u.background_color.is_some()
+            || u.font_color.is_some()
+            || u.hyperlink.is_some()
+            || u.validation.is_some()
+    });
+    if !styled && SpreadsheetFormat::from_path(Path::new(file_path)) == SpreadsheetFormat::Xlsx {
+        let patches: Vec<crate::xlsx_patch::CellPatch> = updates
+            .iter()
+            .map(|u| crate::xlsx_patch::CellPatch {
+                row: u.row,
+                col: u.column,
+                value: u.value.clone(),
+            })
+            .collect();
+        if crate::xlsx_patch::patch_cells(file_path, sheet_name, &patches).is_ok() {
+            return Ok(());
+        }
+    }
+    update_excel_cells_full(file_path, sheet_name, updates)
+}
+
+fn update_excel_cells_full(
+    file_path: &str,
+    sheet_name: &str,
+    updates: Vec<CellUpdate>
 ) -> Result<()> {
     let mut sheets = read_excel_file(file_path)?;
-    
+
     for sheet in &mut sheets {
         if sheet.sheet_name == sheet_name {
             for update in &updates {
@@ -198,22 +800,38 @@ pub fn update_excel_cells(
                     if update.column < sheet.rows[row_idx].len() {
                         // Try to parse as number first
                         if let Ok(num) = update.value.parse::<f64>() {
-                            sheet.rows[row_idx][update.column] = DataValue::Number(num);
+                            sheet.rows[row_idx][update.column] = DataValue::Float(num);
                         } else if update.value.to_lowercase() == "true" {
-                            sheet.rows[row_idx][update.column] = DataValue::Boolean(true);
+                            sheet.rows[row_idx][update.column] = DataValue::Bool(true);
                         } else if update.value.to_lowercase() == "false" {
-                            sheet.rows[row_idx][update.column] = DataValue::Boolean(false);
+                            sheet.rows[row_idx][update.column] = DataValue::Bool(false);
                         } else if update.value.is_empty() {
                             sheet.rows[row_idx][update.column] = DataValue::Empty;
                         } else {
                             sheet.rows[row_idx][update.column] = DataValue::Text(update.value.clone());
                         }
                     }
+
+                    // Carry any presentation metadata through to the write.
+                    if update.background_color.is_some()
+                        || update.font_color.is_some()
+                        || update.hyperlink.is_some()
+                        || update.validation.is_some()
+                    {
+                        sheet.styles.push(CellStyle {
+                            row: update.row as u32,
+                            col: update.column as u16,
+                            background_color: update.background_color.clone(),
+                            font_color: update.font_color.clone(),
+                            hyperlink: update.hyperlink.clone(),
+                            validation: update.validation.clone(),
+                        });
+                    }
                 }
             }
         }
     }
-    
+
     write_excel_file(file_path, sheets)?;
     Ok(())
 }
\ No newline at end of file
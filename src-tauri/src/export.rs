@@ -0,0 +1,125 @@
+use anyhow::{anyhow, Result};
+
+use crate::excel::{read_excel_file, serial_to_datetime, DataValue, ExcelData};
+
+/// Render a cell to its plain-text form for export: dates as ISO-8601,
+/// everything else as its natural string.
+fn render(value: &DataValue) -> String {
+    match value {
+        DataValue::Text(s) => s.clone(),
+        DataValue::Int(i) => i.to_string(),
+        DataValue::Float(n) => n.to_string(),
+        DataValue::Bool(b) => b.to_string(),
+        DataValue::DateTime { serial, is_date } => match (is_date, serial_to_datetime(*serial)) {
+            (true, Some(dt)) => dt.format("%Y-%m-%dT%H:%M:%S").to_string(),
+            _ => serial.to_string(),
+        },
+        DataValue::Duration { hours, minutes } => format!("{}", hours + minutes / 60.0),
+        DataValue::Currency { amount, code } => format!("{} {}", amount, code),
+        DataValue::Empty => String::new(),
+    }
+}
+
+/// Quote a CSV field if it contains a separator, quote, or newline, doubling
+/// any embedded quotes.
+fn csv_quote(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Serialize a sheet to CSV, header row first.
+pub fn sheet_to_csv(sheet: &ExcelData) -> String {
+    let mut out = String::new();
+    out.push_str(
+        &sheet
+            .headers
+            .iter()
+            .map(|h| csv_quote(h))
+            .collect::<Vec<_>>()
+            .join(","),
+    );
+    out.push('\n');
+
+    for row in &sheet.rows {
+        let line = row
+            .iter()
+            .map(|v| csv_quote(&render(v)))
+            .collect::<Vec<_>>()
+            .join(",");
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}
+
+/// Escape the AsciiDoc cell separator so `|` inside content isn't read as a
+/// new cell.
+fn adoc_escape(cell: &str) -> String {
+    cell.replace('|', "\\|")
+}
+
+/// Serialize a sheet to an AsciiDoc table, with column widths proportional to
+/// the widest observed content in each column.
+pub fn sheet_to_adoc(sheet: &ExcelData) -> String {
+    let width = sheet
+        .headers
+        .len()
+        .max(sheet.rows.iter().map(|r| r.len()).max().unwrap_or(0));
+
+    // Proportional weights: the longest string in each column, floored at 1.
+    let mut weights = vec![1usize; width];
+    for (col, header) in sheet.headers.iter().enumerate() {
+        weights[col] = weights[col].max(header.chars().count());
+    }
+    for row in &sheet.rows {
+        for (col, value) in row.iter().enumerate() {
+            weights[col] = weights[col].max(render(value).chars().count());
+        }
+    }
+
+    let cols = weights
+        .iter()
+        .map(|w| w.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let mut out = format!("[cols=\"{}\",options=\"header\"]\n|===\n", cols);
+
+    for header in &sheet.headers {
+        out.push_str(&format!("|{}\n", adoc_escape(header)));
+    }
+    out.push('\n');
+
+    for row in &sheet.rows {
+        for value in row {
+            out.push_str(&format!("|{}\n", adoc_escape(&render(value))));
+        }
+        out.push('\n');
+    }
+    out.push_str("|===\n");
+    out
+}
+
+/// Read `file_path`, locate `sheet_name`, and return its serialization.
+fn export_sheet<F>(file_path: &str, sheet_name: &str, serialize: F) -> Result<String>
+where
+    F: Fn(&ExcelData) -> String,
+{
+    let sheets = read_excel_file(file_path)?;
+    let sheet = sheets
+        .iter()
+        .find(|s| s.sheet_name == sheet_name)
+        .ok_or_else(|| anyhow!("sheet '{}' not found in workbook", sheet_name))?;
+    Ok(serialize(sheet))
+}
+
+pub fn export_csv(file_path: &str, sheet_name: &str) -> Result<String> {
+    export_sheet(file_path, sheet_name, |s| sheet_to_csv(s))
+}
+
+pub fn export_adoc(file_path: &str, sheet_name: &str) -> Result<String> {
+    export_sheet(file_path, sheet_name, |s| sheet_to_adoc(s))
+}
@@ -1,7 +1,15 @@
 mod excel;
+mod schedule;
+mod tasks;
+mod evm;
+mod export;
+mod xlsx_patch;
 mod debug;
 
-use excel::{ExcelData, DataValue, CellUpdate};
+use excel::{ExcelData, ExcelDataWithFormulas, DataValue, CellUpdate, ReadOptions};
+use schedule::{ScheduleInput, ScheduleResult};
+use tasks::{Task, TaskInput, TimeEntry};
+use evm::{EvmInput, EvmResult};
 use tauri::Manager;
 use std::fs;
 use std::path::PathBuf;
@@ -18,6 +26,18 @@ async fn read_excel(file_path: String) -> Result<Vec<ExcelData>, String> {
         .map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+async fn read_excel_with_options(file_path: String, options: ReadOptions) -> Result<Vec<ExcelData>, String> {
+    excel::read_excel_file_with_options(&file_path, &options)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn read_excel_with_formulas(file_path: String) -> Result<Vec<ExcelDataWithFormulas>, String> {
+    excel::read_excel_with_formulas(&file_path)
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 async fn write_excel(file_path: String, data: Vec<ExcelData>) -> Result<(), String> {
     excel::write_excel_file(&file_path, data)
@@ -46,6 +66,52 @@ async fn update_excel_cells(
         .map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+async fn compute_schedule(input: ScheduleInput) -> Result<ScheduleResult, String> {
+    schedule::compute_critical_path(&input)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn export_sheet_csv(file_path: String, sheet_name: String) -> Result<String, String> {
+    export::export_csv(&file_path, &sheet_name).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn export_sheet_adoc(file_path: String, sheet_name: String) -> Result<String, String> {
+    export::export_adoc(&file_path, &sheet_name).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn compute_evm(input: EvmInput) -> Result<EvmResult, String> {
+    evm::compute_evm(&input).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn create_task(project_id: String, input: TaskInput) -> Result<Task, String> {
+    tasks::create_task(&project_id, input).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn update_task(project_id: String, id: u64, input: TaskInput) -> Result<Task, String> {
+    tasks::update_task(&project_id, id, input).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn complete_task(project_id: String, id: u64) -> Result<Task, String> {
+    tasks::complete_task(&project_id, id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn add_task_time_entry(project_id: String, id: u64, entry: TimeEntry) -> Result<Task, String> {
+    tasks::add_time_entry(&project_id, id, entry).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn list_open_tasks(project_id: String) -> Result<Vec<Task>, String> {
+    tasks::open_tasks(&project_id).map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 async fn save_project_notes(project_id: String, notes: String) -> Result<(), String> {
     // Create a notes directory if it doesn't exist
@@ -83,9 +149,20 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             greet,
             read_excel,
+            read_excel_with_options,
+            read_excel_with_formulas,
             write_excel,
             update_cell,
             update_excel_cells,
+            compute_schedule,
+            compute_evm,
+            export_sheet_csv,
+            export_sheet_adoc,
+            create_task,
+            update_task,
+            complete_task,
+            add_task_time_entry,
+            list_open_tasks,
             save_project_notes,
             load_project_notes
         ])
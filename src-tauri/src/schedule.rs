@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use anyhow::{anyhow, Result};
+
+/// A single activity in the project network, keyed by its IFS activity seq.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Activity {
+    pub seq: u64,
+    /// Duration in hours, typically the budgeted hours from the estimate sheet.
+    pub duration: f64,
+}
+
+/// A predecessor → successor relationship between two activities.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Dependency {
+    pub predecessor: u64,
+    pub successor: u64,
+}
+
+/// The scheduling request: the activities plus the edges that connect them.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScheduleInput {
+    pub activities: Vec<Activity>,
+    pub dependencies: Vec<Dependency>,
+}
+
+/// Computed timing for one activity after the forward and backward passes.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ActivitySchedule {
+    pub seq: u64,
+    pub duration: f64,
+    pub earliest_start: f64,
+    pub earliest_finish: f64,
+    pub latest_start: f64,
+    pub latest_finish: f64,
+    pub slack: f64,
+}
+
+/// The result of scheduling: per-activity timing plus the ordered chain of
+/// zero-slack activities that drives the project finish date.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScheduleResult {
+    pub activities: Vec<ActivitySchedule>,
+    pub critical_path: Vec<u64>,
+    pub project_duration: f64,
+}
+
+/// Build the activity DAG and compute the critical path.
+///
+/// Nodes are activity seqs; edges are the supplied predecessor→successor
+/// dependencies. A forward pass sets earliest start/finish, a backward pass
+/// sets latest start/finish, and slack is their difference; the critical path
+/// is the chain of zero-slack activities. Returns an error if the caller
+/// references an unknown activity or if the dependencies contain a cycle.
+pub fn compute_critical_path(input: &ScheduleInput) -> Result<ScheduleResult> {
+    let mut duration: HashMap<u64, f64> = HashMap::new();
+    for a in &input.activities {
+        if duration.insert(a.seq, a.duration).is_some() {
+            return Err(anyhow!("duplicate activity seq {}", a.seq));
+        }
+    }
+
+    let mut successors: HashMap<u64, Vec<u64>> = HashMap::new();
+    let mut predecessors: HashMap<u64, Vec<u64>> = HashMap::new();
+    let mut in_degree: HashMap<u64, usize> = input.activities.iter().map(|a| (a.seq, 0)).collect();
+
+    for dep in &input.dependencies {
+        if !duration.contains_key(&dep.predecessor) {
+            return Err(anyhow!("dependency references unknown activity {}", dep.predecessor));
+        }
+        if !duration.contains_key(&dep.successor) {
+            return Err(anyhow!("dependency references unknown activity {}", dep.successor));
+        }
+        successors.entry(dep.predecessor).or_default().push(dep.successor);
+        predecessors.entry(dep.successor).or_default().push(dep.predecessor);
+        *in_degree.get_mut(&dep.successor).unwrap() += 1;
+    }
+
+    // Kahn's algorithm: repeatedly remove in-degree-0 nodes for a topo order.
+    let mut queue: VecDeque<u64> = in_degree
+        .iter()
+        .filter(|(_, &d)| d == 0)
+        .map(|(&seq, _)| seq)
+        .collect();
+    let mut remaining = in_degree.clone();
+    let mut topo = Vec::with_capacity(input.activities.len());
+    while let Some(seq) = queue.pop_front() {
+        topo.push(seq);
+        for &succ in successors.get(&seq).into_iter().flatten() {
+            let d = remaining.get_mut(&succ).unwrap();
+            *d -= 1;
+            if *d == 0 {
+                queue.push_back(succ);
+            }
+        }
+    }
+    if topo.len() != input.activities.len() {
+        return Err(anyhow!("dependency cycle detected; cannot schedule"));
+    }
+
+    // Forward pass: earliest start/finish in topological order.
+    let mut earliest_start: HashMap<u64, f64> = duration.keys().map(|&s| (s, 0.0)).collect();
+    let mut earliest_finish: HashMap<u64, f64> = HashMap::new();
+    for &seq in &topo {
+        let es = predecessors
+            .get(&seq)
+            .into_iter()
+            .flatten()
+            .map(|p| earliest_finish[p])
+            .fold(0.0_f64, f64::max);
+        earliest_start.insert(seq, es);
+        earliest_finish.insert(seq, es + duration[&seq]);
+    }
+
+    let project_duration = earliest_finish.values().cloned().fold(0.0_f64, f64::max);
+
+    // Backward pass: latest finish/start in reverse topological order.
+    let mut latest_finish: HashMap<u64, f64> = duration.keys().map(|&s| (s, project_duration)).collect();
+    let mut latest_start: HashMap<u64, f64> = HashMap::new();
+    for &seq in topo.iter().rev() {
+        let lf = match successors.get(&seq) {
+            Some(succs) if !succs.is_empty() => {
+                succs.iter().map(|s| latest_start[s]).fold(f64::INFINITY, f64::min)
+            }
+            _ => project_duration,
+        };
+        latest_finish.insert(seq, lf);
+        latest_start.insert(seq, lf - duration[&seq]);
+    }
+
+    let mut activities: Vec<ActivitySchedule> = input
+        .activities
+        .iter()
+        .map(|a| {
+            let es = earliest_start[&a.seq];
+            let ef = earliest_finish[&a.seq];
+            let ls = latest_start[&a.seq];
+            let lf = latest_finish[&a.seq];
+            ActivitySchedule {
+                seq: a.seq,
+                duration: a.duration,
+                earliest_start: es,
+                earliest_finish: ef,
+                latest_start: ls,
+                latest_finish: lf,
+                slack: ls - es,
+            }
+        })
+        .collect();
+    activities.sort_by(|a, b| a.earliest_start.partial_cmp(&b.earliest_start).unwrap());
+
+    // Critical path: the zero-slack activities in topological order.
+    const EPS: f64 = 1e-6;
+    let critical_path: Vec<u64> = topo
+        .iter()
+        .copied()
+        .filter(|seq| (latest_start[seq] - earliest_start[seq]).abs() < EPS)
+        .collect();
+
+    Ok(ScheduleResult {
+        activities,
+        critical_path,
+        project_duration,
+    })
+}
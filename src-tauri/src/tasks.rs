@@ -0,0 +1,185 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+use chrono::{Local, NaiveDate};
+use anyhow::{anyhow, Result};
+
+/// Relative importance of a task, ordered so `High` sorts ahead of `Low`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Priority {
+    Low,
+    Medium,
+    High,
+}
+
+impl Priority {
+    /// Higher rank = more urgent, used to sort open tasks.
+    fn rank(self) -> u8 {
+        match self {
+            Priority::Low => 0,
+            Priority::Medium => 1,
+            Priority::High => 2,
+        }
+    }
+}
+
+/// A single logged effort against a task.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TimeEntry {
+    pub date: NaiveDate,
+    /// Hours spent.
+    pub duration: f64,
+    pub message: String,
+}
+
+/// A trackable unit of project work, replacing the old freeform notes blob.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Task {
+    pub id: u64,
+    pub name: String,
+    pub info: String,
+    pub tags: HashSet<String>,
+    pub dependencies: HashSet<u64>,
+    pub priority: Priority,
+    pub due: Option<NaiveDate>,
+    pub created: NaiveDate,
+    pub completed: Option<NaiveDate>,
+    pub time_entries: Vec<TimeEntry>,
+}
+
+fn store_path(project_id: &str) -> PathBuf {
+    let safe_filename = project_id.replace('/', "_").replace('\\', "_");
+    PathBuf::from("project_tasks").join(format!("{}.json", safe_filename))
+}
+
+fn load_store(project_id: &str) -> Result<Vec<Task>> {
+    let path = store_path(project_id);
+    if path.exists() {
+        let raw = fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&raw)?)
+    } else {
+        Ok(Vec::new())
+    }
+}
+
+fn save_store(project_id: &str, tasks: &[Task]) -> Result<()> {
+    let path = store_path(project_id);
+    fs::create_dir_all(PathBuf::from("project_tasks"))?;
+    fs::write(&path, serde_json::to_string_pretty(tasks)?)?;
+    Ok(())
+}
+
+/// Fields a caller supplies when creating or updating a task. Everything is
+/// optional on update; only the provided fields overwrite the stored task.
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct TaskInput {
+    pub name: Option<String>,
+    pub info: Option<String>,
+    pub tags: Option<HashSet<String>>,
+    pub dependencies: Option<HashSet<u64>>,
+    pub priority: Option<Priority>,
+    pub due: Option<NaiveDate>,
+}
+
+/// Create a new task in the project store and return it.
+pub fn create_task(project_id: &str, input: TaskInput) -> Result<Task> {
+    let mut tasks = load_store(project_id)?;
+    let next_id = tasks.iter().map(|t| t.id).max().unwrap_or(0) + 1;
+    let task = Task {
+        id: next_id,
+        name: input.name.unwrap_or_default(),
+        info: input.info.unwrap_or_default(),
+        tags: input.tags.unwrap_or_default(),
+        dependencies: input.dependencies.unwrap_or_default(),
+        priority: input.priority.unwrap_or(Priority::Medium),
+        due: input.due,
+        created: Local::now().date_naive(),
+        completed: None,
+        time_entries: Vec::new(),
+    };
+    tasks.push(task.clone());
+    save_store(project_id, &tasks)?;
+    Ok(task)
+}
+
+fn find_mut<'a>(tasks: &'a mut [Task], id: u64) -> Result<&'a mut Task> {
+    tasks
+        .iter_mut()
+        .find(|t| t.id == id)
+        .ok_or_else(|| anyhow!("no task {} in project", id))
+}
+
+/// Apply the provided fields to an existing task and return the updated task.
+pub fn update_task(project_id: &str, id: u64, input: TaskInput) -> Result<Task> {
+    let mut tasks = load_store(project_id)?;
+    {
+        let task = find_mut(&mut tasks, id)?;
+        if let Some(name) = input.name {
+            task.name = name;
+        }
+        if let Some(info) = input.info {
+            task.info = info;
+        }
+        if let Some(tags) = input.tags {
+            task.tags = tags;
+        }
+        if let Some(deps) = input.dependencies {
+            task.dependencies = deps;
+        }
+        if let Some(priority) = input.priority {
+            task.priority = priority;
+        }
+        if input.due.is_some() {
+            task.due = input.due;
+        }
+    }
+    let updated = find_mut(&mut tasks, id)?.clone();
+    save_store(project_id, &tasks)?;
+    Ok(updated)
+}
+
+/// Mark a task complete as of today and return it.
+pub fn complete_task(project_id: &str, id: u64) -> Result<Task> {
+    let mut tasks = load_store(project_id)?;
+    let updated = {
+        let task = find_mut(&mut tasks, id)?;
+        task.completed = Some(Local::now().date_naive());
+        task.clone()
+    };
+    save_store(project_id, &tasks)?;
+    Ok(updated)
+}
+
+/// Append a time entry to a task and return the updated task.
+pub fn add_time_entry(project_id: &str, id: u64, entry: TimeEntry) -> Result<Task> {
+    let mut tasks = load_store(project_id)?;
+    let updated = {
+        let task = find_mut(&mut tasks, id)?;
+        task.time_entries.push(entry);
+        task.clone()
+    };
+    save_store(project_id, &tasks)?;
+    Ok(updated)
+}
+
+/// Open (not-yet-completed) tasks, most urgent first: highest priority, then
+/// soonest due date, with undated tasks trailing dated ones.
+pub fn open_tasks(project_id: &str) -> Result<Vec<Task>> {
+    let mut open: Vec<Task> = load_store(project_id)?
+        .into_iter()
+        .filter(|t| t.completed.is_none())
+        .collect();
+    open.sort_by(|a, b| {
+        b.priority
+            .rank()
+            .cmp(&a.priority.rank())
+            .then_with(|| match (a.due, b.due) {
+                (Some(x), Some(y)) => x.cmp(&y),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            })
+    });
+    Ok(open)
+}
@@ -0,0 +1,176 @@
+//! Surgical, in-place edits to `.xlsx` packages.
+//!
+//! An `.xlsx` file is a zip of XML parts; rewriting the whole workbook through
+//! [`read_excel_file`](crate::excel::read_excel_file) +
+//! [`write_excel_file`](crate::excel::write_excel_file) loses everything
+//! `ExcelData` doesn't model — styles, formulas, charts, merged cells, frozen
+//! panes. This module patches only the targeted cells inside
+//! `xl/worksheets/sheetN.xml`, copying the content of every other part of the
+//! package unchanged into the rewritten zip, and batches all edits into a single open/modify/save cycle.
+
+use std::io::{Cursor, Read, Write};
+
+use anyhow::{anyhow, Result};
+use regex::Regex;
+use zip::write::FileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+/// One cell to patch, addressed the same way the in-memory editors address it:
+/// `row` is 1-based, `col` is 0-based.
+pub struct CellPatch {
+    pub row: usize,
+    pub col: usize,
+    /// The new value as typed text; parsed into number/bool/string like the
+    /// full-rewrite path does.
+    pub value: String,
+}
+
+/// Column index (0-based) to its spreadsheet letter(s), e.g. 0 → "A", 27 → "AB".
+fn column_letters(mut col: usize) -> String {
+    let mut out = Vec::new();
+    loop {
+        out.push(b'A' + (col % 26) as u8);
+        if col < 26 {
+            break;
+        }
+        col = col / 26 - 1;
+    }
+    out.reverse();
+    String::from_utf8(out).unwrap()
+}
+
+fn cell_ref(row: usize, col: usize) -> String {
+    format!("{}{}", column_letters(col), row)
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Build the replacement `<c>` element, reusing the original style index `s`
+/// (captured from the cell being replaced) so formatting survives.
+fn build_cell(cell_ref: &str, style_attr: &str, value: &str) -> String {
+    if let Ok(num) = value.parse::<f64>() {
+        format!("<c r=\"{}\"{}><v>{}</v></c>", cell_ref, style_attr, num)
+    } else if value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("false") {
+        let b = if value.eq_ignore_ascii_case("true") { 1 } else { 0 };
+        format!("<c r=\"{}\"{} t=\"b\"><v>{}</v></c>", cell_ref, style_attr, b)
+    } else if value.is_empty() {
+        format!("<c r=\"{}\"{}/>", cell_ref, style_attr)
+    } else {
+        // Inline strings avoid having to touch the shared-strings table.
+        format!(
+            "<c r=\"{}\"{} t=\"inlineStr\"><is><t xml:space=\"preserve\">{}</t></is></c>",
+            cell_ref,
+            style_attr,
+            xml_escape(value)
+        )
+    }
+}
+
+/// Resolve a sheet name to its `xl/worksheets/sheetN.xml` part via
+/// `xl/workbook.xml` and `xl/_rels/workbook.xml.rels`.
+fn resolve_sheet_part(workbook_xml: &str, rels_xml: &str, sheet_name: &str) -> Result<String> {
+    let sheet_re = Regex::new(r#"<sheet[^>]*\bname="([^"]*)"[^>]*\br:id="([^"]*)""#).unwrap();
+    let rid = sheet_re
+        .captures_iter(workbook_xml)
+        .find(|c| c[1] == *sheet_name)
+        .map(|c| c[2].to_string())
+        .ok_or_else(|| anyhow!("sheet '{}' not found in workbook.xml", sheet_name))?;
+
+    let rel_re = Regex::new(r#"<Relationship[^>]*\bId="([^"]*)"[^>]*\bTarget="([^"]*)""#).unwrap();
+    let target = rel_re
+        .captures_iter(rels_xml)
+        .find(|c| c[1] == rid)
+        .map(|c| c[2].to_string())
+        .ok_or_else(|| anyhow!("relationship {} missing from workbook rels", rid))?;
+
+    // Targets are relative to xl/.
+    Ok(format!("xl/{}", target.trim_start_matches('/')))
+}
+
+/// Apply `patches` to `sheet_xml`, returning the modified XML. Errors if a
+/// targeted cell is not already present (the caller then falls back to a full
+/// rewrite rather than risk producing malformed row ordering).
+fn patch_sheet_xml(sheet_xml: &str, patches: &[CellPatch]) -> Result<String> {
+    let mut xml = sheet_xml.to_string();
+    for patch in patches {
+        let cref = cell_ref(patch.row, patch.col);
+        let cell_re = Regex::new(&format!(
+            r#"(?s)<c r="{}"((?: [^>]*?)?)(?:/>|>.*?</c>)"#,
+            regex::escape(&cref)
+        ))
+        .unwrap();
+
+        let captures = cell_re
+            .captures(&xml)
+            .ok_or_else(|| anyhow!("cell {} not present; cannot patch in place", cref))?;
+
+        // Preserve the style index attribute if the original cell carried one.
+        let style_attr = Regex::new(r#"\s+s="\d+""#)
+            .unwrap()
+            .find(&captures[1])
+            .map(|m| m.as_str().to_string())
+            .unwrap_or_default();
+
+        let replacement = build_cell(&cref, &style_attr, &patch.value);
+        xml = cell_re
+            .replace(&xml, regex::NoExpand(replacement.as_str()))
+            .into_owned();
+    }
+    Ok(xml)
+}
+
+/// Patch the given cells of `sheet_name` in place, preserving the rest of the
+/// workbook. Returns an error (for the caller to fall back on) if the package
+/// can't be patched this way.
+pub fn patch_cells(file_path: &str, sheet_name: &str, patches: &[CellPatch]) -> Result<()> {
+    let bytes = std::fs::read(file_path)?;
+    let mut archive = ZipArchive::new(Cursor::new(bytes))?;
+
+    // Slurp every entry so we can rewrite the package, modifying just one part.
+    let mut entries: Vec<(String, Vec<u8>)> = Vec::with_capacity(archive.len());
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        let name = entry.name().to_string();
+        let mut buf = Vec::new();
+        entry.read_to_end(&mut buf)?;
+        entries.push((name, buf));
+    }
+
+    let read_part = |name: &str| -> Result<String> {
+        entries
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, b)| String::from_utf8_lossy(b).into_owned())
+            .ok_or_else(|| anyhow!("missing package part {}", name))
+    };
+
+    let workbook_xml = read_part("xl/workbook.xml")?;
+    let rels_xml = read_part("xl/_rels/workbook.xml.rels")?;
+    let sheet_part = resolve_sheet_part(&workbook_xml, &rels_xml, sheet_name)?;
+
+    let sheet_xml = read_part(&sheet_part)?;
+    let patched = patch_sheet_xml(&sheet_xml, patches)?;
+
+    // Repackage, swapping in the patched worksheet and copying the rest as-is.
+    let mut out = Vec::new();
+    {
+        let mut writer = ZipWriter::new(Cursor::new(&mut out));
+        let options: FileOptions<()> = FileOptions::default();
+        for (name, data) in &entries {
+            writer.start_file(name, options)?;
+            if name == &sheet_part {
+                writer.write_all(patched.as_bytes())?;
+            } else {
+                writer.write_all(data)?;
+            }
+        }
+        writer.finish()?;
+    }
+
+    std::fs::write(file_path, out)?;
+    Ok(())
+}